@@ -0,0 +1,91 @@
+//! A minimal stand-in for `std::io` that lets [`crate::java_read`] and
+//! [`crate::java_write`] compile unchanged whether or not the `std` library
+//! is available, selected via the `no_std` cargo feature.
+
+#[cfg(not(feature = "no_std"))]
+pub use std::io::{Error, ErrorKind, Read, Result, Write};
+
+#[cfg(feature = "no_std")]
+pub use no_std_impl::{Error, ErrorKind, Read, Result, Write};
+
+#[cfg(feature = "no_std")]
+mod no_std_impl {
+    /// See [`std::io::Result`].
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    /// See [`std::io::ErrorKind`], pared down to the variants this crate produces.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ErrorKind {
+        UnexpectedEof,
+        InvalidData,
+    }
+
+    /// See [`std::io::Error`], without the allocating `std` backing.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Error {
+        kind: ErrorKind,
+    }
+
+    impl Error {
+        pub fn kind(&self) -> ErrorKind {
+            self.kind
+        }
+    }
+
+    impl From<ErrorKind> for Error {
+        fn from(kind: ErrorKind) -> Self {
+            Error { kind }
+        }
+    }
+
+    /// See [`std::io::Read`], reduced to the methods `JavaRead` relies on.
+    pub trait Read {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize>;
+
+        fn read_exact(&mut self, mut buf: &mut [u8]) -> Result<()> {
+            while !buf.is_empty() {
+                match self.read(buf)? {
+                    0 => break,
+                    n => buf = &mut buf[n..],
+                }
+            }
+            if buf.is_empty() {
+                Ok(())
+            } else {
+                Err(ErrorKind::UnexpectedEof.into())
+            }
+        }
+    }
+
+    /// See [`std::io::Write`], reduced to the methods `JavaWrite` relies on.
+    pub trait Write {
+        fn write(&mut self, buf: &[u8]) -> Result<usize>;
+
+        fn write_all(&mut self, mut buf: &[u8]) -> Result<()> {
+            while !buf.is_empty() {
+                match self.write(buf)? {
+                    0 => return Err(ErrorKind::UnexpectedEof.into()),
+                    n => buf = &buf[n..],
+                }
+            }
+            Ok(())
+        }
+    }
+
+    // Bridges so the existing `std::io::Cursor`-based tests keep working
+    // even when built against this no_std `Read`/`Write`, without pulling
+    // `std::io::Cursor` into the production no_std path itself.
+    #[cfg(test)]
+    impl<T: AsRef<[u8]>> Read for std::io::Cursor<T> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            std::io::Read::read(self, buf).map_err(|_| ErrorKind::UnexpectedEof.into())
+        }
+    }
+
+    #[cfg(test)]
+    impl Write for std::io::Cursor<alloc::vec::Vec<u8>> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            std::io::Write::write(self, buf).map_err(|_| ErrorKind::UnexpectedEof.into())
+        }
+    }
+}
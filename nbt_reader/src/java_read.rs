@@ -1,5 +1,8 @@
-use std::io;
-use std::io::Read;
+#[cfg(all(feature = "no_std", feature = "alloc"))]
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::io_compat as io;
+use crate::io_compat::Read;
 
 /// See [_Interface DataInput_, Java® Platform, Standard Edition & Java Development Kit Version 19 API Specification](<https://docs.oracle.com/en/java/javase/19/docs/api/java.base/java/io/DataInput.html#readFully(byte%5B%5D)>)
 pub trait JavaRead: Read {
@@ -13,12 +16,44 @@ pub trait JavaRead: Read {
     fn read_double(&mut self) -> io::Result<f64>;
 
     fn read_utf(&mut self) -> io::Result<String>;
+
+    /// Reads a Minecraft protocol VarInt, a LEB128-style variable-length encoding of `i32`.
+    ///
+    /// See [_Protocol, Type:VarInt and VarLong_, wiki.vg](<https://wiki.vg/Protocol#VarInt_and_VarLong>)
+    fn read_var_int(&mut self) -> io::Result<i32>
+    where
+        Self: Sized,
+    {
+        read_var_num(self, 5).map(|v| v as i32)
+    }
+
+    /// Reads a Minecraft protocol VarLong, a LEB128-style variable-length encoding of `i64`.
+    ///
+    /// See [_Protocol, Type:VarInt and VarLong_, wiki.vg](<https://wiki.vg/Protocol#VarInt_and_VarLong>)
+    fn read_var_long(&mut self) -> io::Result<i64>
+    where
+        Self: Sized,
+    {
+        read_var_num(self, 10)
+    }
+}
+
+fn read_var_num<R: JavaRead>(reader: &mut R, max_bytes: u32) -> io::Result<i64> {
+    let mut value: i64 = 0;
+    for position in 0..max_bytes {
+        let byte = reader.read_byte()? as u8;
+        value |= ((byte & 0x7F) as i64) << (7 * position);
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(io::ErrorKind::InvalidData.into())
 }
 
 macro_rules! read_primitive_fn {
     ($name:ident,$type:ty) => {
         fn $name(&mut self) -> io::Result<$type> {
-            let mut buf = [0u8; std::mem::size_of::<$type>()];
+            let mut buf = [0u8; core::mem::size_of::<$type>()];
             self.read_exact(&mut buf)?;
             Ok(<$type>::from_be_bytes(buf))
         }
@@ -45,7 +80,7 @@ impl<R: Read> JavaRead for R {
         let mut code_units: Vec<u16> = Vec::with_capacity(utf_length as usize); // UTF-16
 
         // reads the next continuation byte, strips the prefix and extends to u16
-        fn next_cont_byte(bytes_iter: &mut std::vec::IntoIter<u8>) -> io::Result<u16> {
+        fn next_cont_byte(bytes_iter: &mut impl Iterator<Item = u8>) -> io::Result<u16> {
             let b = bytes_iter
                 .next()
                 .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
@@ -139,4 +174,37 @@ mod tests {
         );
         assert_eq!(DATA.len(), reader.position() as usize);
     }
+
+    #[test]
+    fn read_var_int() {
+        pub const DATA: [u8; 5] = [0xff, 0xff, 0xff, 0xff, 0x0f];
+        let mut reader = Cursor::new(&DATA);
+        assert_eq!(-1, reader.read_var_int().unwrap());
+        assert_eq!(DATA.len(), reader.position() as usize);
+    }
+
+    #[test]
+    fn read_var_int_single_byte() {
+        pub const DATA: [u8; 1] = [0x01];
+        let mut reader = Cursor::new(&DATA);
+        assert_eq!(1, reader.read_var_int().unwrap());
+        assert_eq!(DATA.len(), reader.position() as usize);
+    }
+
+    #[test]
+    fn read_var_int_overflow() {
+        pub const DATA: [u8; 6] = [0xff, 0xff, 0xff, 0xff, 0xff, 0x0f];
+        let mut reader = Cursor::new(&DATA);
+        assert!(reader.read_var_int().is_err());
+    }
+
+    #[test]
+    fn read_var_long() {
+        pub const DATA: [u8; 10] = [
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01,
+        ];
+        let mut reader = Cursor::new(&DATA);
+        assert_eq!(-1, reader.read_var_long().unwrap());
+        assert_eq!(DATA.len(), reader.position() as usize);
+    }
 }
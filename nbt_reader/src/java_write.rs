@@ -0,0 +1,229 @@
+#[cfg(all(feature = "no_std", feature = "alloc"))]
+use alloc::vec::Vec;
+
+use crate::io_compat as io;
+use crate::io_compat::Write;
+
+/// See [_Interface DataOutput_, Java® Platform, Standard Edition & Java Development Kit Version 19 API Specification](<https://docs.oracle.com/en/java/javase/19/docs/api/java.base/java/io/DataOutput.html>)
+pub trait JavaWrite: Write {
+    fn write_byte(&mut self, value: i8) -> io::Result<()>;
+    fn write_short(&mut self, value: i16) -> io::Result<()>;
+    fn write_unsigned_short(&mut self, value: u16) -> io::Result<()>;
+    fn write_int(&mut self, value: i32) -> io::Result<()>;
+    fn write_long(&mut self, value: i64) -> io::Result<()>;
+
+    fn write_float(&mut self, value: f32) -> io::Result<()>;
+    fn write_double(&mut self, value: f64) -> io::Result<()>;
+
+    fn write_utf(&mut self, value: &str) -> io::Result<()>;
+
+    /// Writes a Minecraft protocol VarInt, a LEB128-style variable-length encoding of `i32`.
+    ///
+    /// See [_Protocol, Type:VarInt and VarLong_, wiki.vg](<https://wiki.vg/Protocol#VarInt_and_VarLong>)
+    fn write_var_int(&mut self, value: i32) -> io::Result<()>
+    where
+        Self: Sized,
+    {
+        write_var_num(self, value as u32 as u64)
+    }
+
+    /// Writes a Minecraft protocol VarLong, a LEB128-style variable-length encoding of `i64`.
+    ///
+    /// See [_Protocol, Type:VarInt and VarLong_, wiki.vg](<https://wiki.vg/Protocol#VarInt_and_VarLong>)
+    fn write_var_long(&mut self, value: i64) -> io::Result<()>
+    where
+        Self: Sized,
+    {
+        write_var_num(self, value as u64)
+    }
+}
+
+fn write_var_num<W: JavaWrite>(writer: &mut W, mut value: u64) -> io::Result<()> {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            writer.write_byte(byte as i8)?;
+            return Ok(());
+        }
+        writer.write_byte((byte | 0x80) as i8)?;
+    }
+}
+
+macro_rules! write_primitive_fn {
+    ($name:ident,$type:ty) => {
+        fn $name(&mut self, value: $type) -> io::Result<()> {
+            self.write_all(&value.to_be_bytes())
+        }
+    };
+}
+
+impl<W: Write> JavaWrite for W {
+    write_primitive_fn! { write_byte, i8 }
+    write_primitive_fn! { write_short, i16 }
+    write_primitive_fn! { write_unsigned_short, u16 }
+    write_primitive_fn! { write_int, i32 }
+    write_primitive_fn! { write_long, i64 }
+    write_primitive_fn! { write_float, f32 }
+    write_primitive_fn! { write_double, f64 }
+
+    fn write_utf(&mut self, value: &str) -> io::Result<()> {
+        // https://github.com/openjdk/jdk/blob/030b071db1fb6197a2633a04b20aa95432a903bc/src/java.base/share/classes/java/io/DataOutputStream.java#L306-L360
+
+        let code_units: Vec<u16> = value.encode_utf16().collect();
+
+        let utf_length: usize = code_units
+            .iter()
+            .map(|&c| match c {
+                0 => 2,
+                0x0001..=0x007F => 1,
+                0x0080..=0x07FF => 2,
+                _ => 3,
+            })
+            .sum();
+        if utf_length > u16::MAX as usize {
+            return Err(io::ErrorKind::InvalidData.into());
+        }
+        self.write_unsigned_short(utf_length as u16)?;
+
+        let mut bytes = Vec::with_capacity(utf_length);
+        for c in code_units {
+            if c != 0 && c <= 0x007F {
+                bytes.push(c as u8);
+            } else if c <= 0x07FF {
+                bytes.push(0xC0 | (c >> 6) as u8);
+                bytes.push(0x80 | (c & 0x3F) as u8);
+            } else {
+                bytes.push(0xE0 | (c >> 12) as u8);
+                bytes.push(0x80 | ((c >> 6) & 0x3F) as u8);
+                bytes.push(0x80 | (c & 0x3F) as u8);
+            }
+        }
+        self.write_all(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::java_read::JavaRead;
+
+    #[test]
+    fn write_byte() {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_byte(-64).unwrap();
+        assert_eq!(&[0xc0], buf.get_ref().as_slice());
+    }
+
+    #[test]
+    fn write_short() {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_short(-16384).unwrap();
+        assert_eq!(&[0xc0, 0x0], buf.get_ref().as_slice());
+    }
+
+    #[test]
+    fn write_unsigned_short() {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_unsigned_short(32767).unwrap();
+        assert_eq!(&[0x7f, 0xff], buf.get_ref().as_slice());
+    }
+
+    #[test]
+    fn write_int() {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_int(-1073741824).unwrap();
+        assert_eq!(&[0xc0, 0x0, 0x0, 0x0], buf.get_ref().as_slice());
+    }
+
+    #[test]
+    fn write_long() {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_long(-4611686018427387904).unwrap();
+        assert_eq!(
+            &[0xc0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0],
+            buf.get_ref().as_slice()
+        );
+    }
+
+    #[test]
+    fn write_utf() {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_utf("\u{0041}\u{03BC}\u{0000}\u{121F}").unwrap();
+        assert_eq!(
+            &[0x0, 0x8, 0x41, 0xce, 0xbc, 0xc0, 0x80, 0xe1, 0x88, 0x9f],
+            buf.get_ref().as_slice()
+        );
+    }
+
+    #[test]
+    fn write_var_int() {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_var_int(-1).unwrap();
+        assert_eq!(&[0xff, 0xff, 0xff, 0xff, 0x0f], buf.get_ref().as_slice());
+    }
+
+    #[test]
+    fn write_var_int_single_byte() {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_var_int(1).unwrap();
+        assert_eq!(&[0x01], buf.get_ref().as_slice());
+    }
+
+    #[test]
+    fn round_trip_var_int() {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_var_int(i32::MIN).unwrap();
+        buf.write_var_int(i32::MAX).unwrap();
+        buf.write_var_int(0).unwrap();
+
+        buf.set_position(0);
+        assert_eq!(i32::MIN, buf.read_var_int().unwrap());
+        assert_eq!(i32::MAX, buf.read_var_int().unwrap());
+        assert_eq!(0, buf.read_var_int().unwrap());
+    }
+
+    #[test]
+    fn round_trip_var_long() {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_var_long(i64::MIN).unwrap();
+        buf.write_var_long(i64::MAX).unwrap();
+        buf.write_var_long(0).unwrap();
+
+        buf.set_position(0);
+        assert_eq!(i64::MIN, buf.read_var_long().unwrap());
+        assert_eq!(i64::MAX, buf.read_var_long().unwrap());
+        assert_eq!(0, buf.read_var_long().unwrap());
+    }
+
+    #[test]
+    fn round_trip_utf() {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_utf("hello \u{0000} \u{1F600}").unwrap();
+        buf.set_position(0);
+        assert_eq!("hello \u{0000} \u{1F600}", buf.read_utf().unwrap());
+    }
+
+    #[test]
+    fn round_trip_primitives() {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_byte(-12).unwrap();
+        buf.write_short(-1234).unwrap();
+        buf.write_unsigned_short(1234).unwrap();
+        buf.write_int(-123456).unwrap();
+        buf.write_long(-123456789).unwrap();
+        buf.write_float(1.5).unwrap();
+        buf.write_double(-2.5).unwrap();
+
+        buf.set_position(0);
+        assert_eq!(-12, buf.read_byte().unwrap());
+        assert_eq!(-1234, buf.read_short().unwrap());
+        assert_eq!(1234, buf.read_unsigned_short().unwrap());
+        assert_eq!(-123456, buf.read_int().unwrap());
+        assert_eq!(-123456789, buf.read_long().unwrap());
+        assert_eq!(1.5, buf.read_float().unwrap());
+        assert_eq!(-2.5, buf.read_double().unwrap());
+    }
+}
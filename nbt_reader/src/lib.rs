@@ -0,0 +1,18 @@
+#![cfg_attr(feature = "no_std", no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+// The test targets run on a hosted toolchain even when the `no_std` feature
+// is enabled, so pull `std` back in just for tests (e.g. `std::io::Cursor`
+// as a concrete reader/writer); the production code never references it.
+#[cfg(all(test, feature = "no_std"))]
+extern crate std;
+
+pub mod io_compat;
+pub mod java_read;
+pub mod java_write;
+pub mod nbt;
+
+#[cfg(not(feature = "no_std"))]
+pub mod region;
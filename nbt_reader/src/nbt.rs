@@ -0,0 +1,342 @@
+#[cfg(all(feature = "no_std", feature = "alloc"))]
+use alloc::{string::String, vec::Vec};
+
+use crate::io_compat as io;
+use crate::java_read::JavaRead;
+use crate::java_write::JavaWrite;
+
+/// See [_NBT format_, Minecraft Wiki](<https://minecraft.wiki/w/NBT_format>)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tag {
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    ByteArray(Vec<i8>),
+    String(String),
+    List(Vec<Tag>),
+    Compound(Vec<(String, Tag)>),
+    IntArray(Vec<i32>),
+    LongArray(Vec<i64>),
+}
+
+impl Tag {
+    fn id(&self) -> i8 {
+        match self {
+            Tag::Byte(_) => TAG_BYTE,
+            Tag::Short(_) => TAG_SHORT,
+            Tag::Int(_) => TAG_INT,
+            Tag::Long(_) => TAG_LONG,
+            Tag::Float(_) => TAG_FLOAT,
+            Tag::Double(_) => TAG_DOUBLE,
+            Tag::ByteArray(_) => TAG_BYTE_ARRAY,
+            Tag::String(_) => TAG_STRING,
+            Tag::List(_) => TAG_LIST,
+            Tag::Compound(_) => TAG_COMPOUND,
+            Tag::IntArray(_) => TAG_INT_ARRAY,
+            Tag::LongArray(_) => TAG_LONG_ARRAY,
+        }
+    }
+}
+
+const TAG_END: i8 = 0;
+const TAG_BYTE: i8 = 1;
+const TAG_SHORT: i8 = 2;
+const TAG_INT: i8 = 3;
+const TAG_LONG: i8 = 4;
+const TAG_FLOAT: i8 = 5;
+const TAG_DOUBLE: i8 = 6;
+const TAG_BYTE_ARRAY: i8 = 7;
+const TAG_STRING: i8 = 8;
+const TAG_LIST: i8 = 9;
+const TAG_COMPOUND: i8 = 10;
+const TAG_INT_ARRAY: i8 = 11;
+const TAG_LONG_ARRAY: i8 = 12;
+
+/// Upper bound on how far a declared array/list length is trusted to
+/// pre-size a `Vec`, so a corrupt or hostile length field (read straight
+/// off the wire, up to `i32::MAX`) cannot force a huge upfront allocation
+/// before a single element has actually been read. The vector still grows
+/// to the real length via the read loop; this only caps the initial guess.
+const MAX_PREALLOCATED_LEN: usize = 4096;
+
+/// Rejects a declared array/list length that is negative, rather than
+/// silently treating it as zero.
+fn non_negative_len(length: i32) -> io::Result<usize> {
+    usize::try_from(length).map_err(|_| io::ErrorKind::InvalidData.into())
+}
+
+/// Reads a single named tag, i.e. a one-byte id, its name, and its payload.
+pub fn read_tag<R: JavaRead>(reader: &mut R) -> io::Result<(String, Tag)> {
+    let id = reader.read_byte()?;
+    let name = reader.read_utf()?;
+    let tag = read_payload(reader, id)?;
+    Ok((name, tag))
+}
+
+fn read_payload<R: JavaRead>(reader: &mut R, id: i8) -> io::Result<Tag> {
+    match id {
+        TAG_BYTE => Ok(Tag::Byte(reader.read_byte()?)),
+        TAG_SHORT => Ok(Tag::Short(reader.read_short()?)),
+        TAG_INT => Ok(Tag::Int(reader.read_int()?)),
+        TAG_LONG => Ok(Tag::Long(reader.read_long()?)),
+        TAG_FLOAT => Ok(Tag::Float(reader.read_float()?)),
+        TAG_DOUBLE => Ok(Tag::Double(reader.read_double()?)),
+        TAG_BYTE_ARRAY => {
+            let length = non_negative_len(reader.read_int()?)?;
+            let mut values = Vec::with_capacity(length.min(MAX_PREALLOCATED_LEN));
+            for _ in 0..length {
+                values.push(reader.read_byte()?);
+            }
+            Ok(Tag::ByteArray(values))
+        }
+        TAG_STRING => Ok(Tag::String(reader.read_utf()?)),
+        TAG_LIST => {
+            let element_id = reader.read_byte()?;
+            let length = non_negative_len(reader.read_int()?)?;
+            let mut values = Vec::with_capacity(length.min(MAX_PREALLOCATED_LEN));
+            for _ in 0..length {
+                values.push(read_payload(reader, element_id)?);
+            }
+            Ok(Tag::List(values))
+        }
+        TAG_COMPOUND => {
+            let mut entries = Vec::new();
+            loop {
+                let entry_id = reader.read_byte()?;
+                if entry_id == TAG_END {
+                    break;
+                }
+                let entry_name = reader.read_utf()?;
+                let entry_tag = read_payload(reader, entry_id)?;
+                entries.push((entry_name, entry_tag));
+            }
+            Ok(Tag::Compound(entries))
+        }
+        TAG_INT_ARRAY => {
+            let length = non_negative_len(reader.read_int()?)?;
+            let mut values = Vec::with_capacity(length.min(MAX_PREALLOCATED_LEN));
+            for _ in 0..length {
+                values.push(reader.read_int()?);
+            }
+            Ok(Tag::IntArray(values))
+        }
+        TAG_LONG_ARRAY => {
+            let length = non_negative_len(reader.read_int()?)?;
+            let mut values = Vec::with_capacity(length.min(MAX_PREALLOCATED_LEN));
+            for _ in 0..length {
+                values.push(reader.read_long()?);
+            }
+            Ok(Tag::LongArray(values))
+        }
+        _ => Err(io::ErrorKind::InvalidData.into()),
+    }
+}
+
+/// Writes a single named tag, i.e. a one-byte id, its name, and its payload;
+/// the inverse of [`read_tag`].
+pub fn write_tag<W: JavaWrite>(writer: &mut W, name: &str, tag: &Tag) -> io::Result<()> {
+    writer.write_byte(tag.id())?;
+    writer.write_utf(name)?;
+    write_payload(writer, tag)
+}
+
+fn write_payload<W: JavaWrite>(writer: &mut W, tag: &Tag) -> io::Result<()> {
+    match tag {
+        Tag::Byte(value) => writer.write_byte(*value),
+        Tag::Short(value) => writer.write_short(*value),
+        Tag::Int(value) => writer.write_int(*value),
+        Tag::Long(value) => writer.write_long(*value),
+        Tag::Float(value) => writer.write_float(*value),
+        Tag::Double(value) => writer.write_double(*value),
+        Tag::ByteArray(values) => {
+            writer.write_int(array_len(values.len())?)?;
+            for value in values {
+                writer.write_byte(*value)?;
+            }
+            Ok(())
+        }
+        Tag::String(value) => writer.write_utf(value),
+        Tag::List(values) => {
+            let element_id = values.first().map_or(TAG_END, Tag::id);
+            writer.write_byte(element_id)?;
+            writer.write_int(array_len(values.len())?)?;
+            for value in values {
+                write_payload(writer, value)?;
+            }
+            Ok(())
+        }
+        Tag::Compound(entries) => {
+            for (entry_name, entry_tag) in entries {
+                write_tag(writer, entry_name, entry_tag)?;
+            }
+            writer.write_byte(TAG_END)
+        }
+        Tag::IntArray(values) => {
+            writer.write_int(array_len(values.len())?)?;
+            for value in values {
+                writer.write_int(*value)?;
+            }
+            Ok(())
+        }
+        Tag::LongArray(values) => {
+            writer.write_int(array_len(values.len())?)?;
+            for value in values {
+                writer.write_long(*value)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Converts a `Vec` length to the `i32` NBT uses to declare array/list
+/// lengths, erroring rather than silently truncating if it doesn't fit.
+fn array_len(len: usize) -> io::Result<i32> {
+    i32::try_from(len).map_err(|_| io::ErrorKind::InvalidData.into())
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "no_std")]
+    use alloc::{string::ToString, vec};
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn read_byte_tag() {
+        // TAG_Byte("test"): 42
+        const DATA: [u8; 8] = [0x1, 0x0, 0x4, b't', b'e', b's', b't', 42];
+        let mut reader = Cursor::new(&DATA);
+        let (name, tag) = read_tag(&mut reader).unwrap();
+        assert_eq!("test", name);
+        assert_eq!(Tag::Byte(42), tag);
+    }
+
+    #[test]
+    fn read_compound_tag() {
+        // TAG_Compound(""): { TAG_Byte("b"): 1 }, then TAG_End
+        const DATA: [u8; 9] = [
+            0xa, 0x0, 0x0, // TAG_Compound("")
+            0x1, 0x0, 0x1, b'b', 0x1, // TAG_Byte("b"): 1
+            0x0, // TAG_End
+        ];
+        let mut reader = Cursor::new(&DATA);
+        let (name, tag) = read_tag(&mut reader).unwrap();
+        assert_eq!("", name);
+        assert_eq!(Tag::Compound(vec![("b".to_string(), Tag::Byte(1))]), tag);
+    }
+
+    #[test]
+    fn read_list_tag() {
+        // TAG_List(""): [TAG_Byte: 1, TAG_Byte: 2]
+        const DATA: [u8; 10] = [
+            0x9, 0x0, 0x0, // TAG_List("")
+            0x1, 0x0, 0x0, 0x0, 0x2, // element id, length
+            0x1, 0x2, // elements
+        ];
+        let mut reader = Cursor::new(&DATA);
+        let (name, tag) = read_tag(&mut reader).unwrap();
+        assert_eq!("", name);
+        assert_eq!(Tag::List(vec![Tag::Byte(1), Tag::Byte(2)]), tag);
+    }
+
+    fn round_trip(name: &str, tag: Tag) {
+        let mut buf = Cursor::new(Vec::new());
+        write_tag(&mut buf, name, &tag).unwrap();
+        buf.set_position(0);
+        assert_eq!((name.to_string(), tag), read_tag(&mut buf).unwrap());
+    }
+
+    #[test]
+    fn round_trip_short_tag() {
+        round_trip("short", Tag::Short(-1234));
+    }
+
+    #[test]
+    fn round_trip_int_tag() {
+        round_trip("int", Tag::Int(-123456));
+    }
+
+    #[test]
+    fn round_trip_long_tag() {
+        round_trip("long", Tag::Long(-123456789));
+    }
+
+    #[test]
+    fn round_trip_float_tag() {
+        round_trip("float", Tag::Float(1.5));
+    }
+
+    #[test]
+    fn round_trip_double_tag() {
+        round_trip("double", Tag::Double(-2.5));
+    }
+
+    #[test]
+    fn round_trip_string_tag() {
+        round_trip("string", Tag::String("hello".to_string()));
+    }
+
+    #[test]
+    fn round_trip_byte_array_tag() {
+        round_trip("byte_array", Tag::ByteArray(vec![1, -2, 3]));
+    }
+
+    #[test]
+    fn round_trip_empty_byte_array_tag() {
+        round_trip("empty", Tag::ByteArray(vec![]));
+    }
+
+    #[test]
+    fn round_trip_int_array_tag() {
+        round_trip("int_array", Tag::IntArray(vec![1, -2, 3]));
+    }
+
+    #[test]
+    fn round_trip_long_array_tag() {
+        round_trip("long_array", Tag::LongArray(vec![1, -2, 3]));
+    }
+
+    #[test]
+    fn round_trip_empty_list_tag() {
+        round_trip("empty_list", Tag::List(vec![]));
+    }
+
+    #[test]
+    fn round_trip_nested_compound_tag() {
+        round_trip(
+            "nested",
+            Tag::Compound(vec![
+                ("a".to_string(), Tag::Int(1)),
+                ("b".to_string(), Tag::Compound(vec![("c".to_string(), Tag::Byte(2))])),
+            ]),
+        );
+    }
+
+    #[test]
+    fn read_unknown_tag_id_is_error() {
+        // unknown tag id 0x42, empty name
+        const DATA: [u8; 3] = [0x42, 0x0, 0x0];
+        let mut reader = Cursor::new(&DATA);
+        assert!(read_tag(&mut reader).is_err());
+    }
+
+    #[test]
+    fn read_truncated_array_is_error() {
+        // TAG_ByteArray(""): declared length 4, but only 1 byte follows
+        const DATA: [u8; 8] = [0x7, 0x0, 0x0, 0x0, 0x0, 0x0, 0x4, 0x1];
+        let mut reader = Cursor::new(&DATA);
+        assert!(read_tag(&mut reader).is_err());
+    }
+
+    #[test]
+    fn read_negative_array_length_is_error() {
+        // TAG_ByteArray(""): declared length -1
+        const DATA: [u8; 7] = [0x7, 0x0, 0x0, 0xff, 0xff, 0xff, 0xff];
+        let mut reader = Cursor::new(&DATA);
+        assert!(read_tag(&mut reader).is_err());
+    }
+}
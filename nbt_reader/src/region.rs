@@ -0,0 +1,144 @@
+use std::io;
+use std::io::Read;
+
+use flate2::read::{GzDecoder, ZlibDecoder};
+
+use crate::java_read::JavaRead;
+use crate::nbt::{self, Tag};
+
+const COMPRESSION_GZIP: u8 = 1;
+const COMPRESSION_ZLIB: u8 = 2;
+const COMPRESSION_UNCOMPRESSED: u8 = 3;
+
+/// Reads one chunk's NBT data from a Minecraft region (`.mca`) file body: a
+/// 4-byte big-endian length, a 1-byte compression type, then that many
+/// (length - 1) bytes of compressed NBT.
+///
+/// See [_Anvil file format_, Minecraft Wiki](<https://minecraft.wiki/w/Anvil_file_format>)
+pub fn read_chunk<R: JavaRead>(reader: &mut R) -> io::Result<(String, Tag)> {
+    let length = reader.read_int()?;
+    if length <= 0 {
+        return Err(io::ErrorKind::InvalidData.into());
+    }
+    let compression_type = reader.read_byte()? as u8;
+    let mut bounded = BoundedRead::new(reader, length as u64 - 1);
+
+    match compression_type {
+        COMPRESSION_GZIP => nbt::read_tag(&mut GzDecoder::new(bounded)),
+        COMPRESSION_ZLIB => nbt::read_tag(&mut ZlibDecoder::new(bounded)),
+        COMPRESSION_UNCOMPRESSED => nbt::read_tag(&mut bounded),
+        _ => Err(io::ErrorKind::InvalidData.into()),
+    }
+}
+
+/// A [`Read`] adapter that refuses to read past a declared byte limit,
+/// returning EOF instead of spilling into whatever follows in the
+/// underlying reader. Used to contain a chunk's decompressor to its
+/// declared length, so a corrupt length field in a region file cannot
+/// over-read into the next chunk.
+struct BoundedRead<R> {
+    inner: R,
+    remaining: u64,
+}
+
+impl<R> BoundedRead<R> {
+    fn new(inner: R, limit: u64) -> Self {
+        BoundedRead {
+            inner,
+            remaining: limit,
+        }
+    }
+}
+
+impl<R: Read> Read for BoundedRead<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.remaining == 0 {
+            return Ok(0);
+        }
+        let limit = (buf.len() as u64).min(self.remaining) as usize;
+        let read = self.inner.read(&mut buf[..limit])?;
+        self.remaining -= read as u64;
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use flate2::write::{GzEncoder, ZlibEncoder};
+    use flate2::Compression;
+
+    use super::*;
+    use crate::java_write::JavaWrite;
+
+    #[test]
+    fn bounded_read_truncates_at_limit() {
+        let mut reader = BoundedRead::new(Cursor::new([1u8, 2, 3, 4, 5]), 3);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).unwrap();
+        assert_eq!(vec![1, 2, 3], buf);
+    }
+
+    fn nbt_bytes() -> Vec<u8> {
+        let mut buf = Vec::new();
+        nbt::write_tag(&mut buf, "chunk", &Tag::Byte(42)).unwrap();
+        buf
+    }
+
+    fn chunk_body(compression_type: u8, payload: &[u8]) -> Vec<u8> {
+        let mut body = Vec::new();
+        body.write_int(payload.len() as i32 + 1).unwrap();
+        body.write_byte(compression_type as i8).unwrap();
+        body.extend_from_slice(payload);
+        body
+    }
+
+    #[test]
+    fn read_chunk_uncompressed() {
+        let body = chunk_body(COMPRESSION_UNCOMPRESSED, &nbt_bytes());
+        let mut reader = Cursor::new(body);
+        let (name, tag) = read_chunk(&mut reader).unwrap();
+        assert_eq!("chunk", name);
+        assert_eq!(Tag::Byte(42), tag);
+    }
+
+    #[test]
+    fn read_chunk_gzip() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&nbt_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let body = chunk_body(COMPRESSION_GZIP, &compressed);
+        let mut reader = Cursor::new(body);
+        let (name, tag) = read_chunk(&mut reader).unwrap();
+        assert_eq!("chunk", name);
+        assert_eq!(Tag::Byte(42), tag);
+    }
+
+    #[test]
+    fn read_chunk_zlib() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&nbt_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let body = chunk_body(COMPRESSION_ZLIB, &compressed);
+        let mut reader = Cursor::new(body);
+        let (name, tag) = read_chunk(&mut reader).unwrap();
+        assert_eq!("chunk", name);
+        assert_eq!(Tag::Byte(42), tag);
+    }
+
+    #[test]
+    fn read_chunk_rejects_non_positive_length() {
+        let mut reader = Cursor::new([0u8, 0, 0, 0]);
+        assert!(read_chunk(&mut reader).is_err());
+    }
+
+    #[test]
+    fn read_chunk_rejects_unknown_compression_type() {
+        let body = chunk_body(0xff, &nbt_bytes());
+        let mut reader = Cursor::new(body);
+        assert!(read_chunk(&mut reader).is_err());
+    }
+}